@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// Internal string buffer used to build up the rendered template output
+#[derive(Clone, Debug, Default)]
+pub struct Buffer {
+    buf: String,
+}
+
+impl Buffer {
+    #[inline]
+    pub fn new() -> Buffer {
+        Buffer { buf: String::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Buffer {
+        Buffer {
+            buf: String::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn write_str(&mut self, s: &str) {
+        self.buf.push_str(s);
+    }
+
+    #[inline]
+    pub fn write_char(&mut self, c: char) {
+        self.buf.push(c);
+    }
+
+    /// Appends `bytes` to the buffer, returning `fmt::Error` if it isn't valid UTF-8.
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        match std::str::from_utf8(bytes) {
+            Ok(s) => {
+                self.buf.push_str(s);
+                Ok(())
+            }
+            Err(_) => Err(fmt::Error),
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.buf
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.buf
+    }
+}
+
+impl fmt::Write for Buffer {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buf.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.buf.push(c);
+        Ok(())
+    }
+}
@@ -1,4 +1,10 @@
+use std::borrow::Cow;
 use std::fmt;
+use std::fmt::Display;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 use std::path::{Path, PathBuf};
 
 use super::buffer::Buffer;
@@ -17,28 +23,30 @@ pub trait Render {
     }
 }
 
-// /// Autoref-based stable specialization
-// ///
-// /// Explanation can be found [here](https://github.com/dtolnay/case-studies/blob/master/autoref-specialization/README.md)
-// impl<T: Display> Render for &T {
-//     fn render(&self, b: &mut Buffer) -> fmt::Result {
-//         fmt::write(b, format_args!("{}", self))
-//     }
-// 
-//     fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
-//         struct Wrapper<'a>(&'a mut Buffer);
-// 
-//         impl<'a> fmt::Write for Wrapper<'a> {
-//             #[inline]
-//             fn write_str(&mut self, s: &str) -> fmt::Result {
-//                 escape::escape_to_buf(s, self.0);
-//                 Ok(())
-//             }
-//         }
-// 
-//         fmt::write(&mut Wrapper(b), format_args!("{}", self))
-//     }
-// }
+/// Autoref-based stable specialization
+///
+/// Explanation can be found [here](https://github.com/dtolnay/case-studies/blob/master/autoref-specialization/README.md)
+impl<'a, T: Display> Render for &'a T {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> fmt::Result {
+        fmt::write(b, format_args!("{}", self))
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+        struct Wrapper<'a>(&'a mut Buffer);
+
+        impl<'a> fmt::Write for Wrapper<'a> {
+            #[inline]
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                escape::escape_to_buf(s, self.0);
+                Ok(())
+            }
+        }
+
+        fmt::write(&mut Wrapper(b), format_args!("{}", self))
+    }
+}
 
 impl Render for str {
     #[inline]
@@ -84,6 +92,21 @@ impl Render for String {
     }
 }
 
+impl<'a> Render for Cow<'a, str> {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> fmt::Result {
+        b.write_str(self);
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+        // escape string
+        escape::escape_to_buf(self, b);
+        Ok(())
+    }
+}
+
 impl Render for char {
     #[inline]
     fn render(&self, b: &mut Buffer) -> fmt::Result {
@@ -135,29 +158,80 @@ impl Render for PathBuf {
     }
 }
 
-// impl Render for [u8] {
-//     #[inline]
-//     fn render(&self, b: &mut Buffer) -> fmt::Result {
-//         b.write_bytes(self);
-//         Ok(())
-//     }
-// }
-//
-// impl<'a> Render for &'a [u8] {
-//     #[inline]
-//     fn render(&self, b: &mut Buffer) -> fmt::Result {
-//         b.write_bytes(self);
-//         Ok(())
-//     }
-// }
-//
-// impl Render for Vec<u8> {
-//     #[inline]
-//     fn render(&self, b: &mut Buffer) -> fmt::Result {
-//         b.write_bytes(&**self);
-//         Ok(())
-//     }
-// }
+impl<'a> Render for Cow<'a, Path> {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> fmt::Result {
+        // TODO: speed up on Windows using OsStrExt
+        b.write_str(&self.to_string_lossy());
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+        escape::escape_to_buf(&self.to_string_lossy(), b);
+        Ok(())
+    }
+}
+
+impl<'a> Render for fmt::Arguments<'a> {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> fmt::Result {
+        fmt::write(b, *self)
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+        struct EscapingWrapper<'a>(&'a mut Buffer);
+
+        impl<'a> fmt::Write for EscapingWrapper<'a> {
+            #[inline]
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                escape::escape_to_buf(s, self.0);
+                Ok(())
+            }
+        }
+
+        fmt::write(&mut EscapingWrapper(b), *self)
+    }
+}
+
+impl Render for [u8] {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> fmt::Result {
+        b.write_bytes(self)
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+        let s = std::str::from_utf8(self).map_err(|_| fmt::Error)?;
+        escape::escape_to_buf(s, b);
+        Ok(())
+    }
+}
+
+impl<'a> Render for &'a [u8] {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> fmt::Result {
+        b.write_bytes(self)
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+        (**self).render_escaped(b)
+    }
+}
+
+impl Render for Vec<u8> {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> fmt::Result {
+        b.write_bytes(self)
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+        (**self).render_escaped(b)
+    }
+}
 
 impl Render for bool {
     #[inline]
@@ -199,7 +273,43 @@ macro_rules! render_int {
     }
 }
 
-render_int!(u8, u16, u32, u64, i8, i16, i32, i64, usize, isize);
+render_int!(
+    u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize
+);
+
+macro_rules! render_nonzero_int {
+    ($($nonzero:ty),*) => {
+        $(
+            impl Render for $nonzero {
+                #[inline]
+                fn render(&self, b: &mut Buffer) -> fmt::Result {
+                    self.get().render(b)
+                }
+
+                #[inline]
+                fn render_escaped(&self, b: &mut Buffer) -> fmt::Result {
+                    // write_str without escape
+                    self.render(b)
+                }
+            }
+        )*
+    }
+}
+
+render_nonzero_int!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize
+);
 
 macro_rules! render_float {
     ($($float:ty),*) => {
@@ -255,4 +365,96 @@ mod tests {
         (&&&'&').render_escaped(&mut b).unwrap();
         (&&&&' ').render_escaped(&mut b).unwrap();
     }
+
+    #[test]
+    fn nonzero_integers() {
+        let mut b = Buffer::new();
+        NonZeroU8::new(1).unwrap().render(&mut b).unwrap();
+        NonZeroI32::new(-5).unwrap().render(&mut b).unwrap();
+        NonZeroU128::new(340).unwrap().render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "1-5340");
+    }
+
+    #[test]
+    fn format_args() {
+        let mut b = Buffer::new();
+        format_args!("{} + {} = {}", 1, 2, 3).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "1 + 2 = 3");
+
+        let mut b = Buffer::new();
+        format_args!("<{}>", "a&b")
+            .render_escaped(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "&lt;a&amp;b&gt;");
+    }
+
+    struct CustomDisplay(i32);
+
+    impl fmt::Display for CustomDisplay {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "<CustomDisplay {}>", self.0)
+        }
+    }
+
+    #[test]
+    fn display_fallback() {
+        let mut b = Buffer::new();
+        (&CustomDisplay(42)).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "<CustomDisplay 42>");
+
+        let mut b = Buffer::new();
+        (&CustomDisplay(1)).render_escaped(&mut b).unwrap();
+        (&&CustomDisplay(1)).render_escaped(&mut b).unwrap();
+        assert_eq!(
+            b.as_str(),
+            "&lt;CustomDisplay 1&gt;&lt;CustomDisplay 1&gt;"
+        );
+    }
+
+    #[test]
+    fn cow_str() {
+        let mut b = Buffer::new();
+        let borrowed: Cow<'_, str> = Cow::Borrowed("apple&");
+        let owned: Cow<'_, str> = Cow::Owned(String::from("banana"));
+        borrowed.render_escaped(&mut b).unwrap();
+        owned.render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "apple&amp;banana");
+    }
+
+    #[test]
+    fn cow_path() {
+        let mut b = Buffer::new();
+        let borrowed: Cow<'_, Path> = Cow::Borrowed(Path::new("a&b"));
+        let owned: Cow<'_, Path> = Cow::Owned(PathBuf::from("c/d"));
+        borrowed.render_escaped(&mut b).unwrap();
+        owned.render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "a&amp;bc/d");
+
+        #[cfg(unix)]
+        {
+            use std::ffi::OsStr;
+            use std::os::unix::ffi::OsStrExt;
+
+            let mut b = Buffer::new();
+            let lossy: Cow<'_, Path> =
+                Cow::Owned(PathBuf::from(OsStr::from_bytes(b"bad\xffname")));
+            lossy.render(&mut b).unwrap();
+            assert_eq!(b.as_str(), "bad\u{fffd}name");
+        }
+    }
+
+    #[test]
+    fn byte_slice() {
+        let mut b = Buffer::new();
+        b"<p>hi</p>".render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "<p>hi</p>");
+
+        let mut b = Buffer::new();
+        let v: Vec<u8> = b"a&b".to_vec();
+        v.render_escaped(&mut b).unwrap();
+        assert_eq!(b.as_str(), "a&amp;b");
+
+        let mut b = Buffer::new();
+        assert!([0xff, 0xfe].render(&mut b).is_err());
+    }
 }